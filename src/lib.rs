@@ -0,0 +1,859 @@
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum Tile {
+    Latent(usize),
+    Active(usize),
+    Dead,
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+struct SeenState {
+    tiles: Vec<Tile>,
+    location: Option<usize>,
+    reward: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Action {
+    Advance,
+    CounterClockwise,
+    Clockwise,
+    Collect,
+}
+
+/// Engine knobs that used to be baked constants. `threshold` caps how long a
+/// tile stays `Active` before dying, and doubles as the per-tile reward cap
+/// (`threshold^2`) used by the heuristics below.
+pub struct Config {
+    threshold: usize,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self { threshold: 6 }
+    }
+
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Search-cost bookkeeping from `GameState::solve_with_stats`, so callers
+/// can see the branch-and-bound speedup.
+#[derive(Debug, Clone, Copy)]
+pub struct SolveStats {
+    pub states_seen: usize,
+    pub pruned: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct GameState {
+    tiles: Vec<Tile>,
+    location: Option<usize>,
+    pub start_location: Option<usize>,
+    threshold: usize,
+    pub reward: usize,
+    location_queue: Vec<Option<usize>>,
+    pub action_queue: Vec<Action>,
+}
+
+impl GameState {
+    pub fn new(tiles: Vec<Tile>) -> Self {
+        Self::with_config(tiles, &Config::default())
+    }
+
+    pub fn with_config(tiles: Vec<Tile>, config: &Config) -> Self {
+        Self {
+            tiles,
+            location: None,
+            start_location: None,
+            action_queue: vec![],
+            location_queue: vec![],
+            threshold: config.threshold,
+            reward: 0,
+        }
+    }
+
+    fn step(&mut self) {
+        self.tiles.iter_mut().for_each(|t| match t {
+            Tile::Latent(0) => {
+                *t = Tile::Active(1);
+            }
+            Tile::Latent(v) => {
+                *v -= 1;
+            }
+            Tile::Active(v) => {
+                if *v < self.threshold {
+                    *v += 1;
+                } else {
+                    *t = Tile::Dead;
+                }
+            }
+            _ => {}
+        });
+    }
+
+    fn execute(&mut self, action: Action) {
+        match action {
+            Action::Collect => {
+                let selected = &mut self.tiles[self.location.expect("Swipe without location")];
+
+                if let Tile::Active(v) = selected {
+                    self.reward += (*v).pow(2);
+                }
+
+                *selected = Tile::Dead;
+            }
+            Action::Advance => {
+                self.step();
+            }
+            Action::CounterClockwise => {
+                let location = self.location.expect("Move without location");
+                // watch out for underflow prior to wrap
+                self.location = Some((location + self.tiles.len() - 1) % self.tiles.len());
+                self.step();
+            }
+            Action::Clockwise => {
+                let location = self.location.expect("Move without location");
+                self.location = Some((location + 1) % self.tiles.len());
+                self.step();
+            }
+        }
+        self.action_queue.push(action);
+        self.location_queue.push(self.location);
+    }
+
+    /// Exhaustive memoized DFS, pruned with a branch-and-bound upper bound so
+    /// hopeless branches are abandoned without being expanded.
+    pub fn solve(&self) -> GameState {
+        self.solve_with_stats().0
+    }
+
+    /// Same search as `solve`, also reporting how many states were visited
+    /// and how many branches the branch-and-bound prune discarded.
+    pub fn solve_with_stats(&self) -> (GameState, SolveStats) {
+        let mut seen = std::collections::HashSet::new();
+        let best_so_far = std::cell::Cell::new(0);
+        let pruned = std::cell::Cell::new(0);
+        let result = solve_dfs(self.clone(), &mut seen, &best_so_far, &pruned)
+            .expect("solve should always find a terminal state");
+        let stats = SolveStats {
+            states_seen: seen.len(),
+            pruned: pruned.get(),
+        };
+        (result, stats)
+    }
+
+    /// Level-by-level beam search: trades optimality for tractability on
+    /// boards where `solve` can't finish.
+    pub fn solve_beam(&self, beam_width: usize) -> Option<GameState> {
+        solve_beam_impl(self.clone(), beam_width)
+    }
+
+    /// Monte-Carlo Tree Search within `budget`, for boards too large for
+    /// `solve` or `solve_beam`. Returns the most-visited path from the root,
+    /// finished greedily; the best individual rollout is not kept.
+    pub fn solve_mcts(&self, budget: std::time::Duration) -> GameState {
+        solve_mcts_impl(self.clone(), budget)
+    }
+
+    /// Simulated annealing over (start_location, action sequence)
+    /// candidates, useful when the exact search space is intractable.
+    /// Returns (reward, start_location, actions) for the best candidate
+    /// found; `start_location` is `None` for an already-terminal board.
+    pub fn solve_annealing(
+        &self,
+        iterations: usize,
+        t0: f64,
+        t1: f64,
+    ) -> (usize, Option<usize>, Vec<Action>) {
+        solve_annealing_impl(&self.tiles, iterations, t0, t1)
+    }
+}
+
+fn solve_dfs(
+    state: GameState,
+    seen: &mut std::collections::HashSet<SeenState>,
+    best_so_far: &std::cell::Cell<usize>,
+    pruned: &std::cell::Cell<usize>,
+) -> Option<GameState> {
+    let mut best_state: Option<GameState> = None;
+
+    // A terminal state is always a real candidate, so it must be recognized
+    // before the bound below can ever discard it.
+    if state.tiles.iter().all(|t| matches!(t, Tile::Dead)) {
+        if state.reward > best_so_far.get() {
+            best_so_far.set(state.reward);
+        }
+        return Some(state);
+    }
+
+    // `heuristic` is an admissible upper bound: every surviving tile could at
+    // best be collected at the threshold value, so this never prunes away
+    // the true optimum.
+    let ub = state.reward + heuristic(&state);
+    if ub <= best_so_far.get() {
+        pruned.set(pruned.get() + 1);
+        return None;
+    }
+
+    if seen.contains(&SeenState {
+        tiles: state.tiles.clone(),
+        location: state.location,
+        reward: state.reward,
+    }) {
+        return None;
+    } else {
+        let seenstate = SeenState {
+            tiles: state.tiles.clone(),
+            location: state.location,
+            reward: state.reward,
+        };
+        seen.insert(seenstate);
+    }
+
+    if state.location.is_none() {
+        for i in 0..state.tiles.len() {
+            let mut new_state = state.clone();
+            new_state.location = Some(i);
+            new_state.start_location = Some(i);
+            new_state.step();
+            if let Some(result) = solve_dfs(new_state, seen, best_so_far, pruned) {
+                match best_state {
+                    None => best_state = Some(result),
+                    Some(ref mut best_state) => {
+                        if result.reward > best_state.reward
+                            || (result.reward == best_state.reward
+                                && result.action_queue.len() < best_state.action_queue.len())
+                        {
+                            *best_state = result.clone();
+                        }
+                    }
+                }
+            }
+        }
+    } else {
+        for action in [
+            Action::Advance,
+            Action::CounterClockwise,
+            Action::Clockwise,
+            Action::Collect,
+        ] {
+            if let Some(Action::Collect) = state.action_queue.last()
+                && matches!(action, Action::Collect)
+            {
+                // don't collect twice in a row
+                continue;
+            }
+
+            let mut new_state = state.clone();
+            new_state.execute(action);
+
+            if let Some(result) = solve_dfs(new_state, seen, best_so_far, pruned) {
+                match best_state {
+                    None => best_state = Some(result),
+                    Some(ref mut best_state) => {
+                        if result.reward > best_state.reward
+                            || (result.reward == best_state.reward
+                                && result.action_queue.len() < best_state.action_queue.len())
+                        {
+                            *best_state = result.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+    best_state
+}
+
+// Optimistic upper bound on the reward still obtainable from this state:
+// every surviving tile could in principle be collected at the threshold value.
+fn heuristic(state: &GameState) -> usize {
+    state
+        .tiles
+        .iter()
+        .filter(|t| !matches!(t, Tile::Dead))
+        .count()
+        * state.threshold
+        * state.threshold
+}
+
+fn solve_beam_impl(state: GameState, beam_width: usize) -> Option<GameState> {
+    // An already-terminal board has no start to pick, as in `solve`.
+    if is_terminal(&state) {
+        return Some(state);
+    }
+
+    let mut seen: std::collections::HashSet<SeenState> = std::collections::HashSet::new();
+    let mut best_terminal: Option<GameState> = None;
+
+    let update_best =
+        |candidate: GameState, best_terminal: &mut Option<GameState>| match best_terminal {
+            None => *best_terminal = Some(candidate),
+            Some(best) => {
+                if candidate.reward > best.reward
+                    || (candidate.reward == best.reward
+                        && candidate.action_queue.len() < best.action_queue.len())
+                {
+                    *best = candidate;
+                }
+            }
+        };
+
+    let mut current_beam: Vec<GameState> = Vec::new();
+    for i in 0..state.tiles.len() {
+        let mut new_state = state.clone();
+        new_state.location = Some(i);
+        new_state.start_location = Some(i);
+        new_state.step();
+        current_beam.push(new_state);
+    }
+
+    while !current_beam.is_empty() {
+        let mut children: Vec<GameState> = Vec::new();
+
+        for beam_state in current_beam {
+            if beam_state.tiles.iter().all(|t| matches!(t, Tile::Dead)) {
+                update_best(beam_state, &mut best_terminal);
+                continue;
+            }
+
+            for action in [
+                Action::Advance,
+                Action::CounterClockwise,
+                Action::Clockwise,
+                Action::Collect,
+            ] {
+                if let Some(Action::Collect) = beam_state.action_queue.last()
+                    && matches!(action, Action::Collect)
+                {
+                    // don't collect twice in a row
+                    continue;
+                }
+
+                let mut child = beam_state.clone();
+                child.execute(action);
+
+                let seen_state = SeenState {
+                    tiles: child.tiles.clone(),
+                    location: child.location,
+                    reward: child.reward,
+                };
+                if seen.contains(&seen_state) {
+                    continue;
+                }
+                seen.insert(seen_state);
+
+                children.push(child);
+            }
+        }
+
+        children.sort_by(|a, b| {
+            let eval_a = a.reward + heuristic(a);
+            let eval_b = b.reward + heuristic(b);
+            eval_b.cmp(&eval_a)
+        });
+
+        current_beam = Vec::new();
+        for child in children {
+            if child.tiles.iter().all(|t| matches!(t, Tile::Dead)) {
+                update_best(child, &mut best_terminal);
+            } else if current_beam.len() < beam_width {
+                current_beam.push(child);
+            }
+        }
+    }
+
+    best_terminal
+}
+
+// Minimal xorshift64 PRNG so rollouts and local search don't need an external crate.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn gen_range(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    // Uniform float in [0, 1).
+    fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MctsMove {
+    Start(usize),
+    Play(Action),
+}
+
+fn legal_moves(state: &GameState) -> Vec<MctsMove> {
+    if is_terminal(state) {
+        return vec![];
+    }
+
+    if state.location.is_none() {
+        (0..state.tiles.len()).map(MctsMove::Start).collect()
+    } else {
+        [
+            Action::Advance,
+            Action::CounterClockwise,
+            Action::Clockwise,
+            Action::Collect,
+        ]
+        .into_iter()
+        .filter(|action| {
+            !(matches!(state.action_queue.last(), Some(Action::Collect))
+                && matches!(action, Action::Collect))
+        })
+        .map(MctsMove::Play)
+        .collect()
+    }
+}
+
+fn apply_move(state: &GameState, mv: MctsMove) -> GameState {
+    let mut new_state = state.clone();
+    match mv {
+        MctsMove::Start(i) => {
+            new_state.location = Some(i);
+            new_state.start_location = Some(i);
+            new_state.step();
+        }
+        MctsMove::Play(action) => new_state.execute(action),
+    }
+    new_state
+}
+
+fn is_terminal(state: &GameState) -> bool {
+    state.tiles.iter().all(|t| matches!(t, Tile::Dead))
+}
+
+struct MctsNode {
+    state: GameState,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried: Vec<MctsMove>,
+    visits: usize,
+    total_reward: f64,
+}
+
+// UCB1 score; unvisited children are always selected first.
+fn ucb1(node: &MctsNode, parent_visits: f64, c: f64) -> f64 {
+    if node.visits == 0 {
+        return f64::INFINITY;
+    }
+    let exploitation = node.total_reward / node.visits as f64;
+    let exploration = c * (parent_visits.ln() / node.visits as f64).sqrt();
+    exploitation + exploration
+}
+
+fn select_child(nodes: &[MctsNode], idx: usize, c: f64) -> usize {
+    let parent_visits = nodes[idx].visits as f64;
+    nodes[idx]
+        .children
+        .iter()
+        .copied()
+        .max_by(|&a, &b| {
+            ucb1(&nodes[a], parent_visits, c)
+                .partial_cmp(&ucb1(&nodes[b], parent_visits, c))
+                .unwrap()
+        })
+        .unwrap()
+}
+
+fn solve_mcts_impl(state: GameState, budget: std::time::Duration) -> GameState {
+    let norm = (state.threshold * state.threshold * state.tiles.len()).max(1) as f64;
+    let c = std::f64::consts::SQRT_2;
+
+    let seed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let mut rng = Rng::new(seed);
+
+    let mut nodes: Vec<MctsNode> = vec![MctsNode {
+        untried: legal_moves(&state),
+        state,
+        parent: None,
+        children: vec![],
+        visits: 0,
+        total_reward: 0.0,
+    }];
+
+    let deadline = std::time::Instant::now() + budget;
+    while std::time::Instant::now() < deadline {
+        // Selection: descend by UCB1 until a node has untried moves or no children.
+        let mut node_idx = 0;
+        while nodes[node_idx].untried.is_empty() && !nodes[node_idx].children.is_empty() {
+            node_idx = select_child(&nodes, node_idx, c);
+        }
+
+        // Expansion.
+        if !nodes[node_idx].untried.is_empty() {
+            let mv = nodes[node_idx].untried.pop().unwrap();
+            let child_state = apply_move(&nodes[node_idx].state, mv);
+            let child_idx = nodes.len();
+            nodes.push(MctsNode {
+                untried: legal_moves(&child_state),
+                state: child_state,
+                parent: Some(node_idx),
+                children: vec![],
+                visits: 0,
+                total_reward: 0.0,
+            });
+            nodes[node_idx].children.push(child_idx);
+            node_idx = child_idx;
+        }
+
+        // Simulation: uniformly random legal moves until all tiles are Dead.
+        let mut sim_state = nodes[node_idx].state.clone();
+        while !is_terminal(&sim_state) {
+            let moves = legal_moves(&sim_state);
+            let mv = moves[rng.gen_range(moves.len())];
+            sim_state = apply_move(&sim_state, mv);
+        }
+        let reward = sim_state.reward as f64 / norm;
+
+        // Backpropagation.
+        let mut cur = Some(node_idx);
+        while let Some(idx) = cur {
+            nodes[idx].visits += 1;
+            nodes[idx].total_reward += reward;
+            cur = nodes[idx].parent;
+        }
+    }
+
+    // Walk the tree by most-visited child; each node's state already carries
+    // the full action_queue up to that point.
+    let mut node_idx = 0;
+    while !nodes[node_idx].children.is_empty() {
+        node_idx = nodes[node_idx]
+            .children
+            .iter()
+            .copied()
+            .max_by_key(|&child| nodes[child].visits)
+            .unwrap();
+    }
+
+    // The walk above stops at the tree's frontier, which isn't guaranteed to
+    // be terminal (the budget can run out before it's fully expanded). Finish
+    // the remainder greedily, one ply at a time, by the same reward + heuristic
+    // score `solve_beam` ranks children by, so the returned state is always an
+    // actual finished solution.
+    let mut state = nodes[node_idx].state.clone();
+    while !is_terminal(&state) {
+        state = legal_moves(&state)
+            .into_iter()
+            .map(|mv| apply_move(&state, mv))
+            .max_by_key(|candidate| candidate.reward + heuristic(candidate))
+            .unwrap();
+    }
+    state
+}
+
+// Replay a candidate (start location, action sequence) from a fresh board.
+// Illegal Collects (on a non-Active tile) are harmless: `execute` already
+// yields 0 reward and leaves the tile Dead, matching the real game rules.
+// `start_location` is `None` for an already-terminal board, which never has
+// a start to pick; such a candidate trivially has no reward to collect.
+fn evaluate(tiles: &[Tile], start_location: Option<usize>, actions: &[Action]) -> usize {
+    let Some(start_location) = start_location else {
+        return 0;
+    };
+
+    let mut state = GameState::new(tiles.to_vec());
+    state.location = Some(start_location);
+    state.start_location = Some(start_location);
+    state.step();
+
+    for &action in actions {
+        if is_terminal(&state) {
+            break;
+        }
+        state.execute(action);
+    }
+
+    state.reward
+}
+
+// A uniformly-random feasible sequence, used to seed simulated annealing.
+// Returns `None` for the start location when `tiles` is already all-Dead,
+// since no tile ever becomes Active to pick a start on.
+fn random_feasible(tiles: &[Tile], rng: &mut Rng) -> (Option<usize>, Vec<Action>) {
+    let state = GameState::new(tiles.to_vec());
+    if is_terminal(&state) {
+        return (None, vec![]);
+    }
+
+    let moves = legal_moves(&state);
+    let mut state = apply_move(&state, moves[rng.gen_range(moves.len())]);
+    let start = state.start_location;
+
+    while !is_terminal(&state) {
+        let moves = legal_moves(&state);
+        state = apply_move(&state, moves[rng.gen_range(moves.len())]);
+    }
+
+    (start, state.action_queue)
+}
+
+// One of {insert, delete, swap adjacent, mutate start location}.
+fn propose_neighbor(
+    tiles: &[Tile],
+    start: usize,
+    actions: &[Action],
+    rng: &mut Rng,
+) -> (usize, Vec<Action>) {
+    let mut new_actions = actions.to_vec();
+    let mut new_start = start;
+
+    match rng.gen_range(4) {
+        0 => {
+            let action = [
+                Action::Advance,
+                Action::CounterClockwise,
+                Action::Clockwise,
+                Action::Collect,
+            ][rng.gen_range(4)];
+            let idx = rng.gen_range(new_actions.len() + 1);
+            new_actions.insert(idx, action);
+        }
+        1 => {
+            if !new_actions.is_empty() {
+                let idx = rng.gen_range(new_actions.len());
+                new_actions.remove(idx);
+            }
+        }
+        2 => {
+            if new_actions.len() >= 2 {
+                let idx = rng.gen_range(new_actions.len() - 1);
+                new_actions.swap(idx, idx + 1);
+            }
+        }
+        _ => {
+            new_start = rng.gen_range(tiles.len());
+        }
+    }
+
+    (new_start, new_actions)
+}
+
+fn solve_annealing_impl(
+    tiles: &[Tile],
+    iterations: usize,
+    t0: f64,
+    t1: f64,
+) -> (usize, Option<usize>, Vec<Action>) {
+    let seed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let mut rng = Rng::new(seed);
+
+    let (start, actions) = random_feasible(tiles, &mut rng);
+
+    // An already-terminal board (e.g. "------") has no start to search over:
+    // every candidate scores 0, so there's nothing for local search to do.
+    let Some(mut start) = start else {
+        return (0, None, vec![]);
+    };
+    let mut actions = actions;
+    let mut cur_reward = evaluate(tiles, Some(start), &actions);
+
+    let mut best = (start, actions.clone());
+    let mut best_reward = cur_reward;
+
+    for i in 0..iterations {
+        let t = t0 * (t1 / t0).powf(i as f64 / iterations.max(1) as f64);
+
+        let (new_start, new_actions) = propose_neighbor(tiles, start, &actions, &mut rng);
+        let new_reward = evaluate(tiles, Some(new_start), &new_actions);
+        let delta = new_reward as f64 - cur_reward as f64;
+
+        let accept = delta >= 0.0 || rng.gen_f64() < (delta / t).exp();
+        if accept {
+            start = new_start;
+            actions = new_actions;
+            cur_reward = new_reward;
+
+            if cur_reward > best_reward {
+                best_reward = cur_reward;
+                best = (start, actions.clone());
+            }
+        }
+    }
+
+    (best_reward, Some(best.0), best.1)
+}
+
+/// Parse one puzzle line: a digit per region (latent tile countdown) or `-`
+/// for an already-dead region. Agnostic to the number of regions.
+pub fn parse_board(line: &str) -> Vec<Tile> {
+    line.trim()
+        .chars()
+        .map(|c| match c.to_digit(10) {
+            Some(v) => Tile::Latent(v as usize),
+            None => Tile::Dead,
+        })
+        .collect()
+}
+
+/// Render an action sequence as the human-readable swipe/tap instructions.
+pub fn accumulate(actions: &[Action], start: usize, region_count: usize) {
+    let mut location = start as isize; // cheat offset here initially
+    let region_count = region_count as isize;
+    let mut actions = actions.iter().peekable();
+    while let Some(action) = actions.next() {
+        match *action {
+            Action::Advance => {
+                let mut n = 1;
+                while matches!(actions.peek(), Some(Action::Advance)) {
+                    actions.next();
+                    n += 1;
+                }
+                println!("Tap the active region {n} times");
+            }
+            Action::CounterClockwise => {
+                let mut n = 1;
+                while matches!(actions.peek(), Some(Action::CounterClockwise)) && n <= 3 {
+                    actions.next();
+                    n += 1;
+                }
+                location -= n;
+                location = location.rem_euclid(region_count);
+
+                if matches!(actions.peek(), Some(Action::Collect)) {
+                    actions.next();
+                    println!("Swipe on {}", location);
+                } else {
+                    println!("Tap on {}", location);
+                }
+            }
+            Action::Clockwise => {
+                let mut n = 1;
+                while matches!(actions.peek(), Some(Action::Clockwise)) && n <= 3 {
+                    actions.next();
+                    n += 1;
+                }
+                location += n;
+                location = location.rem_euclid(region_count);
+
+                if matches!(actions.peek(), Some(Action::Collect)) {
+                    actions.next();
+                    println!("Swipe on {}", location);
+                } else {
+                    println!("Tap on {}", location);
+                }
+            }
+            Action::Collect => {
+                println!("Swipe on active region");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_on_an_all_dead_board_does_not_panic() {
+        let game = GameState::new(parse_board("------"));
+        let (solved, stats) = game.solve_with_stats();
+        assert_eq!(solved.reward, 0);
+        assert_eq!(solved.start_location, None);
+        assert_eq!(stats.states_seen, 0);
+    }
+
+    #[test]
+    fn solve_beam_never_beats_the_exact_optimum() {
+        let game = GameState::new(parse_board("000000"));
+        let optimal = game.solve().reward;
+
+        let beam = game.solve_beam(200).expect("beam should find a terminal");
+        assert!(beam.reward > 0);
+        assert!(beam.reward <= optimal);
+    }
+
+    #[test]
+    fn solve_mcts_never_beats_the_exact_optimum() {
+        let game = GameState::new(parse_board("000000"));
+        let optimal = game.solve().reward;
+
+        let mcts = game.solve_mcts(std::time::Duration::from_millis(200));
+        assert!(mcts.reward > 0);
+        assert!(mcts.reward <= optimal);
+    }
+
+    #[test]
+    fn solve_mcts_always_reaches_a_terminal_state_on_a_larger_board() {
+        // Large enough that the tree can't be fully expanded within the
+        // budget, so reconstruction has to finish the frontier's tail itself.
+        let game = GameState::new(parse_board("9999999999999999999999"));
+        let mcts = game.solve_mcts(std::time::Duration::from_millis(200));
+        assert!(mcts.tiles.iter().all(|t| matches!(t, Tile::Dead)));
+    }
+
+    #[test]
+    fn solve_mcts_on_an_all_dead_board_does_not_fabricate_a_start() {
+        let game = GameState::new(parse_board("------"));
+        let mcts = game.solve_mcts(std::time::Duration::from_millis(50));
+        assert_eq!(mcts.reward, 0);
+        assert_eq!(mcts.start_location, None);
+    }
+
+    #[test]
+    fn solve_annealing_never_beats_the_exact_optimum() {
+        let tiles = parse_board("000000");
+        let game = GameState::new(tiles.clone());
+        let optimal = game.solve().reward;
+
+        let (reward, start, actions) = game.solve_annealing(500, 5.0, 0.01);
+        assert_eq!(reward, evaluate(&tiles, start, &actions));
+        assert!(reward > 0);
+        assert!(reward <= optimal);
+    }
+
+    #[test]
+    fn solve_annealing_on_an_all_dead_board_does_not_fabricate_a_start() {
+        let game = GameState::new(parse_board("------"));
+        let (reward, start, actions) = game.solve_annealing(50, 5.0, 0.01);
+        assert_eq!(reward, 0);
+        assert_eq!(start, None);
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn parse_board_reads_digits_and_dashes() {
+        let tiles = parse_board("12-34-");
+        assert_eq!(
+            tiles,
+            vec![
+                Tile::Latent(1),
+                Tile::Latent(2),
+                Tile::Dead,
+                Tile::Latent(3),
+                Tile::Latent(4),
+                Tile::Dead,
+            ]
+        );
+    }
+}
@@ -1,253 +1,135 @@
-#![feature(let_chains)]
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
-enum Tile {
-    Latent(usize),
-    Active(usize),
-    Dead,
+use hexless::{Config, GameState, accumulate, parse_board};
+use std::io::Read;
+use std::time::Duration;
+
+enum Mode {
+    Dfs,
+    Beam,
+    Mcts,
+    Annealing,
 }
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
-struct SeenState {
-    tiles: Vec<Tile>,
-    location: Option<usize>,
-    reward: usize,
-}
-
-#[derive(Debug, Clone)]
-struct GameState {
-    tiles: Vec<Tile>,
-    location: Option<usize>,
-    start_location: Option<usize>,
-    threshold: usize,
-    reward: usize,
-    location_queue: Vec<Option<usize>>,
-    action_queue: Vec<Action>,
+fn read_puzzles(path: Option<&str>) -> std::io::Result<Vec<String>> {
+    let input = match path {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            input
+        }
+    };
+
+    Ok(input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
 }
 
-impl GameState {
-    fn new(tiles: Vec<Tile>) -> Self {
-        Self {
-            tiles,
-            location: None,
-            start_location: None,
-            action_queue: vec![],
-            location_queue: vec![],
-            threshold: 6,
-            reward: 0,
+fn main() -> std::io::Result<()> {
+    let mut human = false;
+    let mut path = None;
+    let mut threshold = None;
+    let mut mode = Mode::Dfs;
+    let mut beam_width = 1000;
+    let mut budget_ms = 200;
+    let mut iterations = 2000;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--human" {
+            human = true;
+        } else if arg == "--threshold" {
+            let value = args.next().expect("--threshold requires a value");
+            threshold = Some(value.parse().expect("--threshold value must be a number"));
+        } else if arg == "--beam-width" {
+            let value = args.next().expect("--beam-width requires a value");
+            beam_width = value.parse().expect("--beam-width value must be a number");
+        } else if arg == "--budget-ms" {
+            let value = args.next().expect("--budget-ms requires a value");
+            budget_ms = value.parse().expect("--budget-ms value must be a number");
+        } else if arg == "--iterations" {
+            let value = args.next().expect("--iterations requires a value");
+            iterations = value.parse().expect("--iterations value must be a number");
+        } else if arg == "--mode" {
+            let value = args.next().expect("--mode requires a value");
+            mode = match value.as_str() {
+                "dfs" => Mode::Dfs,
+                "beam" => Mode::Beam,
+                "mcts" => Mode::Mcts,
+                "annealing" => Mode::Annealing,
+                other => panic!("unknown --mode {other} (expected dfs, beam, mcts, or annealing)"),
+            };
+        } else {
+            path = Some(arg);
         }
     }
 
-    fn step(&mut self) {
-        self.tiles.iter_mut().for_each(|t| match t {
-            Tile::Latent(0) => {
-                *t = Tile::Active(1);
-            }
-            Tile::Latent(v) => {
-                *v -= 1;
-            }
-            Tile::Active(v) => {
-                if *v < self.threshold {
-                    *v += 1;
-                } else {
-                    *t = Tile::Dead;
-                }
-            }
-            _ => {}
-        });
+    let mut config = Config::new();
+    if let Some(threshold) = threshold {
+        config = config.threshold(threshold);
     }
 
-    fn execute(&mut self, action: Action) {
-        match action {
-            Action::Collect => {
-                let selected = &mut self.tiles[self.location.expect("Swipe without location")];
-
-                if let Tile::Active(v) = selected {
-                    self.reward += (*v).pow(2);
-                }
-
-                *selected = Tile::Dead;
-            }
-            Action::Advance => {
-                self.step();
+    for line in read_puzzles(path.as_deref())? {
+        let tiles = parse_board(&line);
+        let region_count = tiles.len();
+        let game = GameState::with_config(tiles, &config);
+
+        let (reward, start, actions) = match mode {
+            Mode::Dfs => {
+                let (best_state, stats) = game.solve_with_stats();
+                eprintln!(
+                    "Number of states: {} (pruned: {})",
+                    stats.states_seen, stats.pruned
+                );
+                (
+                    best_state.reward,
+                    best_state.start_location,
+                    best_state.action_queue,
+                )
             }
-            Action::CounterClockwise => {
-                let location = self.location.expect("Move without location");
-                // watch out for underflow prior to wrap
-                self.location = Some((location + self.tiles.len() - 1) % self.tiles.len());
-                self.step();
+            Mode::Beam => {
+                let best_state = game
+                    .solve_beam(beam_width)
+                    .expect("beam search should find a terminal state");
+                (
+                    best_state.reward,
+                    best_state.start_location,
+                    best_state.action_queue,
+                )
             }
-            Action::Clockwise => {
-                let location = self.location.expect("Move without location");
-                self.location = Some((location + 1) % self.tiles.len());
-                self.step();
+            Mode::Mcts => {
+                let best_state = game.solve_mcts(Duration::from_millis(budget_ms));
+                (
+                    best_state.reward,
+                    best_state.start_location,
+                    best_state.action_queue,
+                )
             }
-        }
-        self.action_queue.push(action);
-        self.location_queue.push(self.location);
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-enum Action {
-    Advance,
-    CounterClockwise,
-    Clockwise,
-    Collect,
-}
-
-// DFS to find max reward
-fn solve(state: GameState, seen: &mut std::collections::HashSet<SeenState>) -> Option<GameState> {
-    let mut best_state: Option<GameState> = None;
-
-    if state.tiles.iter().all(|t| matches!(t, Tile::Dead)) {
-        return Some(state);
-    } else if seen.contains(&SeenState {
-        tiles: state.tiles.clone(),
-        location: state.location,
-        reward: state.reward,
-    }) {
-        return None;
-    } else {
-        let seenstate = SeenState {
-            tiles: state.tiles.clone(),
-            location: state.location,
-            reward: state.reward,
+            Mode::Annealing => game.solve_annealing(iterations, 5.0, 0.01),
         };
-        seen.insert(seenstate);
-    }
-
-    if state.location.is_none() {
-        for i in 0..state.tiles.len() {
-            let mut new_state = state.clone();
-            new_state.location = Some(i);
-            new_state.start_location = Some(i);
-            new_state.step();
-            if let Some(result) = solve(new_state, seen) {
-                match best_state {
-                    None => best_state = Some(result),
-                    Some(ref mut best_state) => {
-                        if result.reward > best_state.reward
-                            || (result.reward == best_state.reward
-                                && result.action_queue.len() < best_state.action_queue.len())
-                        {
-                            *best_state = result.clone();
-                        }
-                    }
-                }
-            }
-        }
-    } else {
-        for action in [
-            Action::Advance,
-            Action::CounterClockwise,
-            Action::Clockwise,
-            Action::Collect,
-        ] {
-            if let Some(Action::Collect) = state.action_queue.last()
-                && matches!(action, Action::Collect)
-            {
-                // don't collect twice in a row
-                continue;
-            }
 
-            let mut new_state = state.clone();
-            new_state.execute(action);
-
-            if let Some(result) = solve(new_state, seen) {
-                match best_state {
-                    None => best_state = Some(result),
-                    Some(ref mut best_state) => {
-                        if result.reward > best_state.reward
-                            || (result.reward == best_state.reward
-                                && result.action_queue.len() < best_state.action_queue.len())
-                        {
-                            *best_state = result.clone();
-                        }
-                    }
-                }
-            }
-        }
-    }
-    best_state
-}
-
-fn accumulate(actions: &[Action], start: usize) {
-    let mut location = start as isize; // cheat offset here initially
-    let mut actions = actions.iter().peekable();
-    while let Some(action) = actions.next() {
-        match *action {
-            Action::Advance => {
-                let mut n = 1;
-                while matches!(actions.peek(), Some(Action::Advance)) {
-                    actions.next();
-                    n += 1;
-                }
-                println!("Tap the active region {n} times");
-            }
-            Action::CounterClockwise => {
-                let mut n = 1;
-                while matches!(actions.peek(), Some(Action::CounterClockwise)) && n <= 3 {
-                    actions.next();
-                    n += 1;
-                }
-                location -= n;
-                location = location.rem_euclid(6);
-
-                if matches!(actions.peek(), Some(Action::Collect)) {
-                    actions.next();
-                    println!("Swipe on {}", location);
-                } else {
-                    println!("Tap on {}", location);
-                }
-            }
-            Action::Clockwise => {
-                let mut n = 1;
-                while matches!(actions.peek(), Some(Action::Clockwise)) && n <= 3 {
-                    actions.next();
-                    n += 1;
-                }
-                location += n;
-                location = location.rem_euclid(6);
-
-                if matches!(actions.peek(), Some(Action::Collect)) {
-                    actions.next();
-                    println!("Swipe on {}", location);
-                } else {
-                    println!("Tap on {}", location);
+        // A board that is already all-Dead (e.g. "------") never picks a
+        // start location, since no tile ever became Active to collect.
+        if human {
+            match start {
+                Some(start) => {
+                    println!("Tap on {start} to start");
+                    accumulate(&actions, start, region_count);
                 }
+                None => println!("Board is already solved; no actions needed"),
             }
-            Action::Collect => {
-                println!("Swipe on active region");
-            }
+        } else {
+            let start = start.map_or_else(|| "-".to_string(), |s| s.to_string());
+            let actions = actions
+                .iter()
+                .map(|action| format!("{action:?}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            println!("reward={reward} start={start} actions={actions}");
         }
     }
-}
-
-fn main() {
-    loop {
-        println!("Pick one region to be \"0\" and, the rest of the regions are enumerated clockwise from there.");
-        println!("Enter the number of latent tiles for each region going clockwise from \"0\" (white trapezoid distance from edge, starting from zero), and - for dead tiles, then press enter.");
-        println!("This solver is agnostic to the number of regions, so make sure to be typing exactly 6 characters, if that is what you intend.");
-        let mut tiles = Vec::new();
 
-        let mut input = String::new();
-        std::io::stdin().read_line(&mut input).unwrap();
-        for c in input.chars() {
-            match c.to_digit(10) {
-                Some(v) => tiles.push(Tile::Latent(v as usize)),
-                None => tiles.push(Tile::Dead),
-            }
-        }
-
-        let game = GameState::new(tiles);
-
-        let mut seen = std::collections::HashSet::new();
-        let best_state = solve(game.clone(), &mut seen).unwrap();
-        println!("Number of states: {}", seen.len());
-        println!();
-
-        println!("Tap on {} to start", best_state.start_location.unwrap());
-        accumulate(&best_state.action_queue, best_state.start_location.unwrap());
-        println!();
-    }
+    Ok(())
 }